@@ -0,0 +1,256 @@
+//! Bit-level reading and writing of arbitrary-width fields, tracking position
+//! with a [DataSize] rather than a raw byte/bit count.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::datasize::{DataSize, DataSizeError};
+
+/// Bit order to assemble/disassemble a multi-bit field with: most-significant
+/// bit first for [Endian::Big], least-significant bit first for [Endian::Little].
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+impl Default for Endian {
+    /// Resolve to the target CPU's native endianness.
+    fn default() -> Endian {
+        #[cfg(target_endian = "big")]
+        { Endian::Big }
+        #[cfg(target_endian = "little")]
+        { Endian::Little }
+    }
+}
+
+/// The maximum width, in bits, supported by a single read or write.
+const MAX_FIELD_WIDTH_BITS: u64 = 64;
+
+/// Reads arbitrary bit-width fields out of a `&[u8]`, tracking the current
+/// cursor offset as a [DataSize].
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    position: DataSize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Create a new [BitReader] over `data`, starting at bit offset 0.
+    pub fn new(data: &'a [u8]) -> BitReader<'a> {
+        BitReader { data, position: DataSize::new_from_bits(0) }
+    }
+
+    /// Return the current cursor offset into the underlying data.
+    pub fn position(&self) -> DataSize {
+        self.position
+    }
+
+    /// Return how much of `total` is left to read from the current position.
+    pub fn remaining(&self, total: DataSize) -> DataSize {
+        total.checked_sub(self.position).unwrap_or_else(|| DataSize::new_from_bits(0))
+    }
+
+    /// Read `width.bits()` bits starting at the current offset, assembling them
+    /// MSB-first for [Endian::Big] and LSB-first for [Endian::Little], then
+    /// advance the cursor.
+    pub fn read_bits(&mut self, width: DataSize, endian: Endian) -> Result<u64, DataSizeError> {
+        let width_bits = width.bits();
+        if width_bits > MAX_FIELD_WIDTH_BITS {
+            return Err(DataSizeError::WidthTooLarge { width: width_bits });
+        }
+
+        let total_bits = (self.data.len() as u64) * 8;
+        let start = self.position.bits();
+        if start + width_bits > total_bits {
+            return Err(DataSizeError::OutOfBits {
+                requested: width_bits,
+                available: total_bits.saturating_sub(start),
+            });
+        }
+
+        let mut value: u64 = 0;
+        for i in 0..width_bits {
+            let bit_index = start + i;
+            let byte = self.data[(bit_index / 8) as usize];
+            let bit = (byte >> (7 - (bit_index % 8))) & 1;
+            match endian {
+                Endian::Big => value = (value << 1) | bit as u64,
+                Endian::Little => value |= (bit as u64) << i,
+            }
+        }
+        self.position = DataSize::new_from_bits(start + width_bits);
+
+        Ok(value)
+    }
+}
+
+/// Writes arbitrary bit-width fields into a growable byte buffer, tracking
+/// the current cursor offset as a [DataSize].
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub struct BitWriter {
+    data: Vec<u8>,
+    position: DataSize,
+}
+
+#[cfg(feature = "alloc")]
+impl BitWriter {
+    /// Create a new, empty [BitWriter].
+    pub fn new() -> BitWriter {
+        BitWriter { data: Vec::new(), position: DataSize::new_from_bits(0) }
+    }
+
+    /// Return the current cursor offset into the buffer written so far.
+    pub fn position(&self) -> DataSize {
+        self.position
+    }
+
+    /// Return how much of `total` is left to write from the current position.
+    pub fn remaining(&self, total: DataSize) -> DataSize {
+        total.checked_sub(self.position).unwrap_or_else(|| DataSize::new_from_bits(0))
+    }
+
+    /// Write the low `width.bits()` bits of `value` starting at the current offset,
+    /// MSB-first for [Endian::Big] and LSB-first for [Endian::Little], zero-padding
+    /// the final partial byte, then advance the cursor.
+    pub fn write_bits(&mut self, value: u64, width: DataSize, endian: Endian) -> Result<(), DataSizeError> {
+        let width_bits = width.bits();
+        if width_bits > MAX_FIELD_WIDTH_BITS {
+            return Err(DataSizeError::WidthTooLarge { width: width_bits });
+        }
+
+        let start = self.position.bits();
+        let end = start + width_bits;
+        let needed_bytes = end.div_ceil(8);
+        if self.data.len() < needed_bytes as usize {
+            self.data.resize(needed_bytes as usize, 0);
+        }
+
+        for i in 0..width_bits {
+            let bit = match endian {
+                Endian::Big => (value >> (width_bits - 1 - i)) & 1,
+                Endian::Little => (value >> i) & 1,
+            };
+            if bit == 1 {
+                let bit_index = start + i;
+                let byte_index = (bit_index / 8) as usize;
+                let shift = 7 - (bit_index % 8);
+                self.data[byte_index] |= 1 << shift;
+            }
+        }
+        self.position = DataSize::new_from_bits(end);
+
+        Ok(())
+    }
+
+    /// Consume this [BitWriter], returning the bytes written so far.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for BitWriter {
+    fn default() -> BitWriter {
+        BitWriter::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_bits_big_endian() {
+        let data = [0b1010_0000];
+        let mut reader = BitReader::new(&data);
+        assert_eq!(reader.read_bits(DataSize::new_from_bits(4), Endian::Big).unwrap(), 0b1010);
+        assert_eq!(reader.position(), DataSize::new_from_bits(4));
+    }
+
+    #[test]
+    fn test_read_bits_little_endian() {
+        let data = [0b1010_0000];
+        let mut reader = BitReader::new(&data);
+        assert_eq!(reader.read_bits(DataSize::new_from_bits(4), Endian::Little).unwrap(), 0b0101);
+    }
+
+    #[test]
+    fn test_read_bits_straddles_byte_boundary() {
+        let data = [0b0000_1111, 0b1111_0000];
+        let mut reader = BitReader::new(&data);
+        assert_eq!(reader.read_bits(DataSize::new_from_bits(4), Endian::Big).unwrap(), 0);
+        assert_eq!(reader.read_bits(DataSize::new_from_bits(8), Endian::Big).unwrap(), 0b1111_1111);
+        assert_eq!(reader.read_bits(DataSize::new_from_bits(4), Endian::Big).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_read_bits_width_too_large() {
+        let data = [0u8; 16];
+        let mut reader = BitReader::new(&data);
+        assert_eq!(
+            reader.read_bits(DataSize::new_from_bits(65), Endian::Big),
+            Err(DataSizeError::WidthTooLarge { width: 65 })
+        );
+    }
+
+    #[test]
+    fn test_read_bits_out_of_bits() {
+        let data = [0u8; 1];
+        let mut reader = BitReader::new(&data);
+        assert_eq!(
+            reader.read_bits(DataSize::new_from_bits(9), Endian::Big),
+            Err(DataSizeError::OutOfBits { requested: 9, available: 8 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_write_then_read_round_trip() {
+        let mut writer = BitWriter::new();
+        writer.write_bits(0b101, DataSize::new_from_bits(3), Endian::Big).unwrap();
+        writer.write_bits(0b11001, DataSize::new_from_bits(5), Endian::Big).unwrap();
+        let bytes = writer.into_bytes();
+        assert_eq!(bytes, [0b101_11001]);
+
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read_bits(DataSize::new_from_bits(3), Endian::Big).unwrap(), 0b101);
+        assert_eq!(reader.read_bits(DataSize::new_from_bits(5), Endian::Big).unwrap(), 0b11001);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_write_bits_pads_final_byte_with_zero() {
+        let mut writer = BitWriter::new();
+        writer.write_bits(0b1, DataSize::new_from_bits(1), Endian::Big).unwrap();
+        assert_eq!(writer.into_bytes(), [0b1000_0000]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_write_bits_width_too_large() {
+        let mut writer = BitWriter::new();
+        assert_eq!(
+            writer.write_bits(0, DataSize::new_from_bits(65), Endian::Big),
+            Err(DataSizeError::WidthTooLarge { width: 65 })
+        );
+    }
+
+    #[test]
+    fn test_remaining() {
+        let data = [0u8; 2];
+        let mut reader = BitReader::new(&data);
+        let total = DataSize::new_from_bytes(2);
+        reader.read_bits(DataSize::new_from_bits(4), Endian::Big).unwrap();
+        assert_eq!(reader.remaining(total), DataSize::new_from_bits(12));
+    }
+
+    #[test]
+    fn test_endian_default_matches_target() {
+        #[cfg(target_endian = "little")]
+        assert_eq!(Endian::default(), Endian::Little);
+        #[cfg(target_endian = "big")]
+        assert_eq!(Endian::default(), Endian::Big);
+    }
+}
@@ -1,78 +1,369 @@
-use std::fmt::{Display, Debug, Formatter, Result};
-use std::ops::{Add, Sub};
+use core::fmt::{Display, Debug, Formatter, Result};
+use core::ops::{Add, Sub};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
 
-/// An amount of data, modeled as a number of a bits and readable
-/// as an amount of bits, bytes, kilobytes or megabytes.
+#[cfg(all(feature = "alloc", feature = "std"))]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+/// Which family of units a [DataSize] prefers when formatting itself for display:
+/// decimal (kilo/mega/giga/tera, 1000-based) or binary/IEC (kibi/mebi/gibi/tebi,
+/// 1024-based).
+#[derive(PartialEq, Debug, Copy, Clone, Default)]
+pub enum UnitFamily {
+    #[default]
+    Decimal,
+    Binary,
+}
+
+/// An amount of data, modeled as a number of bits and readable as an amount of
+/// bits, bytes, or any of the decimal (kilo/mega/giga/tera) or binary/IEC
+/// (kibi/mebi/gibi/tebi) units built on top of them.
 ///
-/// Can represent a maximum of u32::max_limit() bits.
-#[derive(PartialEq, PartialOrd, Debug, Copy, Clone)]
+/// Can represent a maximum of u64::MAX bits.
+#[derive(Debug, Copy, Clone)]
 pub struct DataSize {
-    // Using u32 here is an arbitrary limit
-    num_bits: u32
+    num_bits: u64,
+    // Which unit family Display should prefer for this value.
+    display_family: UnitFamily,
+}
+
+impl PartialEq for DataSize {
+    fn eq(&self, other: &Self) -> bool {
+        self.num_bits == other.num_bits
+    }
+}
+
+impl PartialOrd for DataSize {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.num_bits.partial_cmp(&other.num_bits)
+    }
+}
+
+/// Errors returned by the non-panicking `checked_`/`try_` surface of [DataSize].
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum DataSizeError {
+    /// Adding `lhs` and `rhs` (in bits) would overflow a u64.
+    Overflow { lhs: u64, rhs: u64 },
+    /// Subtracting `rhs` from `lhs` (in bits) would underflow (go negative).
+    Underflow { lhs: u64, rhs: u64 },
+    /// `value`, in its requested unit, doesn't fit in a u64 as a number of bits.
+    TooLargeForBits { value: u64 },
+    /// A bit-level read/write was asked for a field wider than 64 bits.
+    WidthTooLarge { width: u64 },
+    /// A bit-level read asked for more bits than remain in the source.
+    OutOfBits { requested: u64, available: u64 },
+}
+
+impl Display for DataSizeError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            DataSizeError::Overflow { lhs, rhs } =>
+                write!(f, "Addition results in an overflow: {} + {} bits can't fit in a u64", lhs, rhs),
+            DataSizeError::Underflow { lhs, rhs } =>
+                write!(f, "Subtraction results in a negative number: {} - {}", lhs, rhs),
+            DataSizeError::TooLargeForBits { value } =>
+                write!(f, "Unsupported value: {}, it cannot fit in a u64 as a number of bits", value),
+            DataSizeError::WidthTooLarge { width } =>
+                write!(f, "Unsupported width: {} bits, the maximum supported width is 64 bits", width),
+            DataSizeError::OutOfBits { requested, available } =>
+                write!(f, "Requested {} bits but only {} bits remain", requested, available),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DataSizeError {}
+
+/// Errors returned when parsing a [DataSize] from a human-readable string, e.g.
+/// via [DataSize]'s [FromStr] impl.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(PartialEq, Debug, Clone)]
+pub enum ParseDataSizeError {
+    /// The string didn't contain both a number and a unit, e.g. `""` or `"kb"`.
+    Malformed { input: String },
+    /// The numeric portion couldn't be parsed as a u64.
+    InvalidNumber { input: String },
+    /// The unit portion didn't match any known unit name.
+    UnknownUnit { unit: String },
+    /// The number was valid but too large to fit in a u64 number of bits.
+    OutOfRange(DataSizeError),
+}
+
+#[cfg(feature = "alloc")]
+impl Display for ParseDataSizeError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            ParseDataSizeError::Malformed { input } =>
+                write!(f, "'{}' is not a valid data size: expected a number followed by a unit", input),
+            ParseDataSizeError::InvalidNumber { input } =>
+                write!(f, "'{}' is not a valid number", input),
+            ParseDataSizeError::UnknownUnit { unit } =>
+                write!(f, "'{}' is not a recognized data size unit", unit),
+            ParseDataSizeError::OutOfRange(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+impl Error for ParseDataSizeError {}
+
+#[cfg(feature = "alloc")]
+impl From<DataSizeError> for ParseDataSizeError {
+    fn from(e: DataSizeError) -> Self {
+        ParseDataSizeError::OutOfRange(e)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for DataSize {
+    type Err = ParseDataSizeError;
+
+    /// Parse a human-readable size string, e.g. `"4 kilobytes"`, `"2MB"`, `"512 bits"`
+    /// or `"1 byte"`. Whitespace between the number and unit is optional, and unit
+    /// names are case-insensitive and accepted in either their long (`megabytes`) or
+    /// short (`mb`) form.
+    fn from_str(s: &str) -> core::result::Result<DataSize, ParseDataSizeError> {
+        let trimmed = s.trim();
+        if !trimmed.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(ParseDataSizeError::Malformed { input: s.to_string() });
+        }
+        let split_at = trimmed.find(|c: char| !c.is_ascii_digit()).unwrap_or(trimmed.len());
+        let (number_part, unit_part) = trimmed.split_at(split_at);
+        if unit_part.trim().is_empty() {
+            return Err(ParseDataSizeError::Malformed { input: s.to_string() });
+        }
+
+        let number: u64 = number_part.parse()
+            .map_err(|_| ParseDataSizeError::InvalidNumber { input: number_part.to_string() })?;
+        let unit = unit_part.trim().to_ascii_lowercase();
+
+        match unit.as_str() {
+            "bit" | "bits" => Ok(DataSize::new_from_bits(number)),
+            "byte" | "bytes" | "b" => Ok(DataSize::try_from_bytes(number)?),
+            "kilobyte" | "kilobytes" | "kb" => Ok(DataSize::try_from_kilobytes(number)?),
+            "megabyte" | "megabytes" | "mb" => Ok(DataSize::try_from_megabytes(number)?),
+            "gigabyte" | "gigabytes" | "gb" => Ok(DataSize::try_from_gigabytes(number)?),
+            "terabyte" | "terabytes" | "tb" => Ok(DataSize::try_from_terabytes(number)?),
+            "kibibyte" | "kibibytes" | "kib" => Ok(DataSize::try_from_kibibytes(number)?),
+            "mebibyte" | "mebibytes" | "mib" => Ok(DataSize::try_from_mebibytes(number)?),
+            "gibibyte" | "gibibytes" | "gib" => Ok(DataSize::try_from_gibibytes(number)?),
+            "tebibyte" | "tebibytes" | "tib" => Ok(DataSize::try_from_tebibytes(number)?),
+            _ => Err(ParseDataSizeError::UnknownUnit { unit: unit_part.trim().to_string() }),
+        }
+    }
 }
 
 impl DataSize {
+    /// The smallest [DataSize]: 0 bits.
+    pub const MIN: DataSize = DataSize { num_bits: 0, display_family: UnitFamily::Decimal };
+    /// The largest [DataSize] representable: u64::MAX bits.
+    pub const MAX: DataSize = DataSize { num_bits: u64::MAX, display_family: UnitFamily::Decimal };
+
     /// Create a DataSize from a number of bits
-    pub fn new_from_bits(num_bits: u32) -> DataSize {
-        DataSize { num_bits }
+    pub fn new_from_bits(num_bits: u64) -> DataSize {
+        DataSize { num_bits, display_family: UnitFamily::Decimal }
     }
 
     /// Create a DataSize from a number of bytes
-    pub fn new_from_bytes(num_bytes: u32) -> DataSize {
-        match num_bytes.checked_mul(DataSize::BYTE_IN_BITS) {
-            Some(num_bits) => DataSize { num_bits },
-            _ => panic!("Unsupported number of bytes: {}, it cannot fit in a u32 as a number of bits", num_bytes)
-        }
+    pub fn new_from_bytes(num_bytes: u64) -> DataSize {
+        Self::try_from_bytes(num_bytes).unwrap_or_else(|e| panic!("{}", e))
     }
 
     /// Create a DataSize from a number of kilobytes
-    pub fn new_from_kilobytes(num_kilobytes: u32) -> DataSize {
-        match num_kilobytes.checked_mul(DataSize::KILOBYTE_IN_BITS) {
-            Some(num_bits) => DataSize { num_bits },
-            _ => panic!("Unsupported number of kilobytes: {}, it cannot fit in a u32 as a number of bits", num_kilobytes)
-        }
+    pub fn new_from_kilobytes(num_kilobytes: u64) -> DataSize {
+        Self::try_from_kilobytes(num_kilobytes).unwrap_or_else(|e| panic!("{}", e))
     }
 
     /// Create a DataSize from a number of megabytes
-    pub fn new_from_megabytes(num_megabytes: u32) -> DataSize {
-        match num_megabytes.checked_mul(DataSize::MEGABYTE_IN_BITS) {
-            Some(num_bits) => DataSize { num_bits },
-            _ => panic!("Unsupported number of megabytes: {}, it cannot fit in a u32 as a number of bits", num_megabytes)
-        }
+    pub fn new_from_megabytes(num_megabytes: u64) -> DataSize {
+        Self::try_from_megabytes(num_megabytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a DataSize from a number of gigabytes
+    pub fn new_from_gigabytes(num_gigabytes: u64) -> DataSize {
+        Self::try_from_gigabytes(num_gigabytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a DataSize from a number of terabytes
+    pub fn new_from_terabytes(num_terabytes: u64) -> DataSize {
+        Self::try_from_terabytes(num_terabytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a DataSize from a number of kibibytes (1024 bytes), displaying as a
+    /// binary/IEC unit by default.
+    pub fn new_from_kibibytes(num_kibibytes: u64) -> DataSize {
+        Self::try_from_kibibytes(num_kibibytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a DataSize from a number of mebibytes (1024 kibibytes), displaying as a
+    /// binary/IEC unit by default.
+    pub fn new_from_mebibytes(num_mebibytes: u64) -> DataSize {
+        Self::try_from_mebibytes(num_mebibytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a DataSize from a number of gibibytes (1024 mebibytes), displaying as a
+    /// binary/IEC unit by default.
+    pub fn new_from_gibibytes(num_gibibytes: u64) -> DataSize {
+        Self::try_from_gibibytes(num_gibibytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a DataSize from a number of tebibytes (1024 gibibytes), displaying as a
+    /// binary/IEC unit by default.
+    pub fn new_from_tebibytes(num_tebibytes: u64) -> DataSize {
+        Self::try_from_tebibytes(num_tebibytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a DataSize from a number of bytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_bytes(num_bytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_bytes, DataSize::BYTE_IN_BITS, UnitFamily::Decimal)
+    }
+
+    /// Create a DataSize from a number of kilobytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_kilobytes(num_kilobytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_kilobytes, DataSize::KILOBYTE_IN_BITS, UnitFamily::Decimal)
+    }
+
+    /// Create a DataSize from a number of megabytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_megabytes(num_megabytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_megabytes, DataSize::MEGABYTE_IN_BITS, UnitFamily::Decimal)
+    }
+
+    /// Create a DataSize from a number of gigabytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_gigabytes(num_gigabytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_gigabytes, DataSize::GIGABYTE_IN_BITS, UnitFamily::Decimal)
+    }
+
+    /// Create a DataSize from a number of terabytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_terabytes(num_terabytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_terabytes, DataSize::TERABYTE_IN_BITS, UnitFamily::Decimal)
+    }
+
+    /// Create a DataSize from a number of kibibytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_kibibytes(num_kibibytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_kibibytes, DataSize::KIBIBYTE_IN_BITS, UnitFamily::Binary)
+    }
+
+    /// Create a DataSize from a number of mebibytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_mebibytes(num_mebibytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_mebibytes, DataSize::MEBIBYTE_IN_BITS, UnitFamily::Binary)
+    }
+
+    /// Create a DataSize from a number of gibibytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_gibibytes(num_gibibytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_gibibytes, DataSize::GIBIBYTE_IN_BITS, UnitFamily::Binary)
+    }
+
+    /// Create a DataSize from a number of tebibytes, returning a [DataSizeError] instead of
+    /// panicking if it doesn't fit in a u64 number of bits.
+    pub fn try_from_tebibytes(num_tebibytes: u64) -> core::result::Result<DataSize, DataSizeError> {
+        Self::try_from_units(num_tebibytes, DataSize::TEBIBYTE_IN_BITS, UnitFamily::Binary)
+    }
+
+    fn try_from_units(
+        num_units: u64,
+        bits_per_unit: u64,
+        display_family: UnitFamily,
+    ) -> core::result::Result<DataSize, DataSizeError> {
+        num_units.checked_mul(bits_per_unit)
+            .map(|num_bits| DataSize { num_bits, display_family })
+            .ok_or(DataSizeError::TooLargeForBits { value: num_units })
+    }
+
+    /// Return the unit family this [DataSize] prefers when formatted for display.
+    pub fn unit_family(&self) -> UnitFamily {
+        self.display_family
+    }
+
+    /// Return a copy of this [DataSize] that prefers `family` when formatted for display.
+    pub fn with_unit_family(mut self, family: UnitFamily) -> DataSize {
+        self.display_family = family;
+        self
     }
 
     /// Return the number of bits represented by this DataSize
-    pub fn bits(&self) -> u32 { self.num_bits }
+    pub fn bits(&self) -> u64 { self.num_bits }
     /// Return the (truncated) number of bytes represented by this DataSize
-    pub fn bytes(&self) -> u32 { self.num_bits / 8 }
+    pub fn bytes(&self) -> u64 { self.num_bits / DataSize::BYTE_IN_BITS }
     /// Return the (truncated) number of kilobytes represented by this DataSize
-    pub fn kilobytes(&self) -> u32 { self.bytes() / 1000 }
+    pub fn kilobytes(&self) -> u64 { self.num_bits / DataSize::KILOBYTE_IN_BITS }
     /// Return the (truncated) number of megabytes represented by this DataSize
-    pub fn megabytes(&self) -> u32 { self.kilobytes() / 1000 }
+    pub fn megabytes(&self) -> u64 { self.num_bits / DataSize::MEGABYTE_IN_BITS }
+    /// Return the (truncated) number of gigabytes represented by this DataSize
+    pub fn gigabytes(&self) -> u64 { self.num_bits / DataSize::GIGABYTE_IN_BITS }
+    /// Return the (truncated) number of terabytes represented by this DataSize
+    pub fn terabytes(&self) -> u64 { self.num_bits / DataSize::TERABYTE_IN_BITS }
+    /// Return the (truncated) number of kibibytes represented by this DataSize
+    pub fn kibibytes(&self) -> u64 { self.num_bits / DataSize::KIBIBYTE_IN_BITS }
+    /// Return the (truncated) number of mebibytes represented by this DataSize
+    pub fn mebibytes(&self) -> u64 { self.num_bits / DataSize::MEBIBYTE_IN_BITS }
+    /// Return the (truncated) number of gibibytes represented by this DataSize
+    pub fn gibibytes(&self) -> u64 { self.num_bits / DataSize::GIBIBYTE_IN_BITS }
+    /// Return the (truncated) number of tebibytes represented by this DataSize
+    pub fn tebibytes(&self) -> u64 { self.num_bits / DataSize::TEBIBYTE_IN_BITS }
 
     /// Return the max value this [DataSize] can hold
-    pub fn max_value(&self) -> u32 {
-        let mut max_value = 0u32;
-        for _ in 0..self.bits() - 1 {
-            max_value |= 1;
-            max_value <<= 1;
+    pub fn max_value(&self) -> u64 {
+        if self.bits() >= 64 {
+            return u64::MAX;
         }
-        // Do the last 'or' here so we don't shift again
-        max_value | 1
+        (1u64 << self.bits()) - 1
+    }
+
+    /// Add `other` to this [DataSize], returning `None` instead of panicking on overflow.
+    pub fn checked_add(self, other: Self) -> Option<DataSize> {
+        self.num_bits.checked_add(other.num_bits)
+            .map(|num_bits| DataSize { num_bits, display_family: self.display_family })
+    }
+
+    /// Subtract `other` from this [DataSize], returning `None` instead of panicking on
+    /// underflow.
+    pub fn checked_sub(self, other: Self) -> Option<DataSize> {
+        self.num_bits.checked_sub(other.num_bits)
+            .map(|num_bits| DataSize { num_bits, display_family: self.display_family })
     }
 
-    const BYTE_IN_BITS: u32 = 8;
-    const KILOBYTE_IN_BITS: u32 = 1000 * DataSize::BYTE_IN_BITS;
-    const MEGABYTE_IN_BITS: u32 = 1000 * DataSize::KILOBYTE_IN_BITS;
+    /// Add `other` to this [DataSize], returning a [DataSizeError] instead of panicking on
+    /// overflow.
+    pub fn try_add(self, other: Self) -> core::result::Result<DataSize, DataSizeError> {
+        self.checked_add(other).ok_or(DataSizeError::Overflow { lhs: self.num_bits, rhs: other.num_bits })
+    }
+
+    /// Subtract `other` from this [DataSize], returning a [DataSizeError] instead of panicking
+    /// on underflow.
+    pub fn try_sub(self, other: Self) -> core::result::Result<DataSize, DataSizeError> {
+        self.checked_sub(other).ok_or(DataSizeError::Underflow { lhs: self.num_bits, rhs: other.num_bits })
+    }
+
+    const BYTE_IN_BITS: u64 = 8;
+    const KILOBYTE_IN_BITS: u64 = 1000 * DataSize::BYTE_IN_BITS;
+    const MEGABYTE_IN_BITS: u64 = 1000 * DataSize::KILOBYTE_IN_BITS;
+    const GIGABYTE_IN_BITS: u64 = 1000 * DataSize::MEGABYTE_IN_BITS;
+    const TERABYTE_IN_BITS: u64 = 1000 * DataSize::GIGABYTE_IN_BITS;
+    const KIBIBYTE_IN_BITS: u64 = 1024 * DataSize::BYTE_IN_BITS;
+    const MEBIBYTE_IN_BITS: u64 = 1024 * DataSize::KIBIBYTE_IN_BITS;
+    const GIBIBYTE_IN_BITS: u64 = 1024 * DataSize::MEBIBYTE_IN_BITS;
+    const TEBIBYTE_IN_BITS: u64 = 1024 * DataSize::GIBIBYTE_IN_BITS;
 }
 
 impl Add for DataSize {
     type Output = Self;
     fn add(self, other: Self) -> Self {
-        match self.num_bits.checked_add(other.num_bits) {
-            Some(new_total) => DataSize { num_bits: new_total },
-            _ => panic!("Addition results in an overflow: {} + {} bits can't fit in a u32", self.num_bits, other.num_bits)
-        }
+        self.try_add(other).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
@@ -80,37 +371,95 @@ impl Sub for DataSize {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        match self.num_bits.checked_sub(other.num_bits) {
-            Some(num_bits) => DataSize { num_bits },
-            _ => panic!("Subtraction results in a negative number: {} - {}", self.num_bits, other.num_bits),
+        self.try_sub(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl DataSize {
+    /// All units in `family`, from largest to smallest, ending in byte and bit.
+    fn unit_table(family: UnitFamily) -> [(u64, &'static str, &'static str); 6] {
+        match family {
+            UnitFamily::Decimal => [
+                (DataSize::TERABYTE_IN_BITS, "terabyte", "terabytes"),
+                (DataSize::GIGABYTE_IN_BITS, "gigabyte", "gigabytes"),
+                (DataSize::MEGABYTE_IN_BITS, "megabyte", "megabytes"),
+                (DataSize::KILOBYTE_IN_BITS, "kilobyte", "kilobytes"),
+                (DataSize::BYTE_IN_BITS, "byte", "bytes"),
+                (1, "bit", "bits"),
+            ],
+            UnitFamily::Binary => [
+                (DataSize::TEBIBYTE_IN_BITS, "tebibyte", "tebibytes"),
+                (DataSize::GIBIBYTE_IN_BITS, "gibibyte", "gibibytes"),
+                (DataSize::MEBIBYTE_IN_BITS, "mebibyte", "mebibytes"),
+                (DataSize::KIBIBYTE_IN_BITS, "kibibyte", "kibibytes"),
+                (DataSize::BYTE_IN_BITS, "byte", "bytes"),
+                (1, "bit", "bits"),
+            ],
+        }
+    }
+
+    /// Return the index, in [DataSize::unit_table], of the largest unit this value
+    /// holds at least one whole one of.
+    fn largest_nonzero_unit_index(&self, family: UnitFamily) -> usize {
+        let table = Self::unit_table(family);
+        table.iter().position(|&(bits_per_unit, _, _)| self.num_bits >= bits_per_unit)
+            .unwrap_or(table.len() - 1)
+    }
+
+    /// Return the largest non-zero (truncated) unit and its name for this value, in
+    /// the unit family this [DataSize] prefers for display.
+    fn display_value_and_unit(&self) -> (u64, &'static str) {
+        let table = Self::unit_table(self.display_family);
+        let (bits_per_unit, singular, plural) = table[self.largest_nonzero_unit_index(self.display_family)];
+        let value = self.num_bits / bits_per_unit;
+        (value, if value == 1 { singular } else { plural })
+    }
+
+    /// Render this [DataSize] using the largest non-zero unit in `family`, with
+    /// `precision` digits after the decimal point, rounding (not truncating) the
+    /// least-significant displayed digit. E.g. 1.5 megabytes with precision 2
+    /// renders as `"1.50 megabytes"`.
+    ///
+    /// If rounding the value in its initially-chosen unit reaches that unit's
+    /// multiplier (e.g. rounds up to 1000 kilobytes), the unit is bumped up to the
+    /// next one so the result never reads like `"1000 kilobytes"` instead of
+    /// `"1 megabyte"`.
+    ///
+    /// Requires the `alloc` and `std` features (the latter for floating-point
+    /// rounding, which isn't available in bare `core`).
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    pub fn format_with(&self, precision: usize, family: UnitFamily) -> String {
+        let table = Self::unit_table(family);
+        let mut index = self.largest_nonzero_unit_index(family);
+        let factor = 10f64.powi(precision as i32);
+
+        loop {
+            let (bits_per_unit, singular, plural) = table[index];
+            let value = self.num_bits as f64 / bits_per_unit as f64;
+            let rounded = (value * factor).round() / factor;
+
+            if index > 0 {
+                let (larger_bits_per_unit, _, _) = table[index - 1];
+                if rounded >= larger_bits_per_unit as f64 / bits_per_unit as f64 {
+                    index -= 1;
+                    continue;
+                }
+            }
+
+            let descriptor = if rounded == 1.0 { singular } else { plural };
+            return format!("{:.precision$} {}", rounded, descriptor, precision = precision);
         }
     }
 }
 
 impl Display for DataSize {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        // Not sure if there's a good 'match' statement that could be
-        // used here, since I'm trying to avoid calling every function
-        // when it's likely not all will be needed (we can stop at
-        // the first one that's non-zero).
-        let (value, descriptor)  = if self.megabytes() == 1 {
-            (self.megabytes(), "megabyte")
-        } else if self.megabytes() > 1 {
-            (self.megabytes(), "megabytes")
-        } else if self.kilobytes() == 1 {
-            (self.kilobytes(), "kilobyte")
-        } else if self.kilobytes() > 1 {
-            (self.kilobytes(), "kilobytes")
-        } else if self.bytes() == 1 {
-            (self.bytes(), "byte")
-        } else if self.bytes() > 1 {
-            (self.bytes(), "bytes")
-        } else if self.bits() == 1 {
-            (self.bits(), "bit")
-        } else {
-            (self.bits(), "bits")
-        };
+        #[cfg(all(feature = "alloc", feature = "std"))]
+        if let Some(precision) = f.precision() {
+            return write!(f, "{}", self.format_with(precision, self.display_family));
+        }
 
+        let (value, descriptor) = self.display_value_and_unit();
         write!(f, "{} {}", value, descriptor)
     }
 }
@@ -133,7 +482,15 @@ mod tests {
     #[test]
     #[should_panic]
     fn test_megabytes_overflow() {
-        DataSize::new_from_megabytes(u32::max_value());
+        DataSize::new_from_megabytes(u64::MAX);
+    }
+
+    #[test]
+    fn test_try_from_megabytes_overflow() {
+        assert_eq!(
+            DataSize::try_from_megabytes(u64::MAX),
+            Err(DataSizeError::TooLargeForBits { value: u64::MAX })
+        );
     }
 
     #[test]
@@ -146,10 +503,23 @@ mod tests {
     #[should_panic]
     fn test_addition_overflow() {
         #[allow(unused_must_use)] {
-            DataSize::new_from_bits(u32::max_value()) + DataSize::new_from_bits(1);
+            DataSize::new_from_bits(u64::MAX) + DataSize::new_from_bits(1);
         }
     }
 
+    #[test]
+    fn test_checked_add_overflow() {
+        assert_eq!(DataSize::new_from_bits(u64::MAX).checked_add(DataSize::new_from_bits(1)), None);
+    }
+
+    #[test]
+    fn test_try_add_overflow() {
+        assert_eq!(
+            DataSize::new_from_bits(u64::MAX).try_add(DataSize::new_from_bits(1)),
+            Err(DataSizeError::Overflow { lhs: u64::MAX, rhs: 1 })
+        );
+    }
+
     #[test]
     fn test_subtraction() {
         assert_eq!(DataSize::new_from_bits(4) - DataSize::new_from_bits(2), DataSize::new_from_bits(2));
@@ -164,10 +534,169 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_checked_sub_underflow() {
+        assert_eq!(DataSize::new_from_bits(1).checked_sub(DataSize::new_from_bits(3)), None);
+    }
+
+    #[test]
+    fn test_try_sub_underflow() {
+        assert_eq!(
+            DataSize::new_from_bits(1).try_sub(DataSize::new_from_bits(3)),
+            Err(DataSizeError::Underflow { lhs: 1, rhs: 3 })
+        );
+    }
+
     #[test]
     fn test_max_value() {
         assert_eq!(DataSize::new_from_bits(2).max_value(), 3);
         assert_eq!(DataSize::new_from_bytes(2).max_value(), 65535);
     }
 
+    #[test]
+    fn test_min_max_consts() {
+        assert_eq!(DataSize::MIN.bits(), 0);
+        assert_eq!(DataSize::MAX.bits(), u64::MAX);
+    }
+
+    #[test]
+    fn test_gigabytes_and_terabytes() {
+        assert_eq!(DataSize::new_from_gigabytes(1).bytes(), 1_000_000_000);
+        assert_eq!(DataSize::new_from_terabytes(1).bytes(), 1_000_000_000_000);
+    }
+
+    #[test]
+    fn test_binary_units() {
+        assert_eq!(DataSize::new_from_kibibytes(1).bytes(), 1024);
+        assert_eq!(DataSize::new_from_mebibytes(1).bytes(), 1024 * 1024);
+        assert_eq!(DataSize::new_from_gibibytes(1).bytes(), 1024 * 1024 * 1024);
+        assert_eq!(DataSize::new_from_tebibytes(1).bytes(), 1024 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_decimal_and_binary_compare_by_bits_only() {
+        assert_eq!(DataSize::new_from_kibibytes(1), DataSize::new_from_bytes(1024));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display_decimal_family() {
+        assert_eq!(DataSize::new_from_bytes(1536).to_string(), "1 kilobyte");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_display_binary_family() {
+        let size = DataSize::new_from_bytes(1536).with_unit_family(UnitFamily::Binary);
+        assert_eq!(size.to_string(), "1 kibibyte");
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    fn test_format_with_precision() {
+        let size = DataSize::new_from_bytes(1_500_000);
+        assert_eq!(size.format_with(2, UnitFamily::Decimal), "1.50 megabytes");
+        assert_eq!(size.format_with(0, UnitFamily::Decimal), "2 megabytes");
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    fn test_format_with_rounds_not_truncates() {
+        // 1,999,999 bytes is 1.999999 megabytes, which should round up to 2.00,
+        // not truncate down to 1.99.
+        let size = DataSize::new_from_bytes(1_999_999);
+        assert_eq!(size.format_with(2, UnitFamily::Decimal), "2.00 megabytes");
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    fn test_format_with_singular() {
+        let size = DataSize::new_from_bytes(1_000_000);
+        assert_eq!(size.format_with(2, UnitFamily::Decimal), "1.00 megabyte");
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    fn test_format_with_rounding_promotes_to_next_unit() {
+        // 999,999 bytes is 999.999 kilobytes, which rounds to 1000 kilobytes at
+        // precision 0 -- that should be reported as 1 megabyte instead.
+        let size = DataSize::new_from_bytes(999_999);
+        assert_eq!(size.format_with(0, UnitFamily::Decimal), "1 megabyte");
+        assert_eq!(size.format_with(2, UnitFamily::Decimal), "1.00 megabyte");
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    fn test_display_honors_formatter_precision() {
+        let size = DataSize::new_from_bytes(1_500_000);
+        assert_eq!(format!("{:.2}", size), "1.50 megabytes");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str_long_units() {
+        assert_eq!("4 kilobytes".parse(), Ok(DataSize::new_from_kilobytes(4)));
+        assert_eq!("512 bits".parse(), Ok(DataSize::new_from_bits(512)));
+        assert_eq!("1 byte".parse(), Ok(DataSize::new_from_bytes(1)));
+        assert_eq!("2 megabytes".parse(), Ok(DataSize::new_from_megabytes(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str_short_units_no_whitespace() {
+        assert_eq!("2MB".parse(), Ok(DataSize::new_from_megabytes(2)));
+        assert_eq!("4kb".parse(), Ok(DataSize::new_from_kilobytes(4)));
+        assert_eq!("1b".parse(), Ok(DataSize::new_from_bytes(1)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str_case_insensitive() {
+        assert_eq!("2Mb".parse(), Ok(DataSize::new_from_megabytes(2)));
+        assert_eq!("2 KILOBYTES".parse(), Ok(DataSize::new_from_kilobytes(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str_binary_units() {
+        assert_eq!("4 kibibytes".parse(), Ok(DataSize::new_from_kibibytes(4)));
+        assert_eq!("2GiB".parse(), Ok(DataSize::new_from_gibibytes(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str_unknown_unit() {
+        assert_eq!(
+            "4 petabytes".parse::<DataSize>(),
+            Err(ParseDataSizeError::UnknownUnit { unit: "petabytes".to_string() })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str_malformed() {
+        assert_eq!(
+            "512".parse::<DataSize>(),
+            Err(ParseDataSizeError::Malformed { input: "512".to_string() })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_str_invalid_number() {
+        assert_eq!(
+            "99999999999999999999999999999999 bits".parse::<DataSize>(),
+            Err(ParseDataSizeError::InvalidNumber { input: "99999999999999999999999999999999".to_string() })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    fn test_from_str_out_of_range() {
+        let s = format!("{} megabytes", u64::MAX);
+        assert_eq!(
+            s.parse::<DataSize>(),
+            Err(ParseDataSizeError::OutOfRange(DataSizeError::TooLargeForBits { value: u64::MAX }))
+        );
+    }
 }
@@ -3,7 +3,7 @@
 #[macro_export]
 macro_rules! bits {
     ($num_bits:expr) => {
-        $crate::datasize::DataSize::new_from_bits($num_bits as u32)
+        $crate::datasize::DataSize::new_from_bits($num_bits as u64)
     };
 }
 
@@ -12,7 +12,7 @@ macro_rules! bits {
 #[macro_export]
 macro_rules! bytes {
     ($num_bytes:expr) => {
-        $crate::datasize::DataSize::new_from_bytes($num_bytes as u32)
+        $crate::datasize::DataSize::new_from_bytes($num_bytes as u64)
     }
 }
 
@@ -21,7 +21,7 @@ macro_rules! bytes {
 #[macro_export]
 macro_rules! kilobytes {
     ($num_kilobytes:expr) => {
-        $crate::datasize::DataSize::new_from_kilobytes($num_kilobytes as u32)
+        $crate::datasize::DataSize::new_from_kilobytes($num_kilobytes as u64)
     }
 }
 
@@ -30,7 +30,61 @@ macro_rules! kilobytes {
 #[macro_export]
 macro_rules! megabytes {
     ($num_megabytes:expr) => {
-        $crate::datasize::DataSize::new_from_megabytes($num_megabytes as u32)
+        $crate::datasize::DataSize::new_from_megabytes($num_megabytes as u64)
+    }
+}
+
+/// Create a DataSize from a number of gigabytes
+/// ex: gigabytes!(4)
+#[macro_export]
+macro_rules! gigabytes {
+    ($num_gigabytes:expr) => {
+        $crate::datasize::DataSize::new_from_gigabytes($num_gigabytes as u64)
+    }
+}
+
+/// Create a DataSize from a number of terabytes
+/// ex: terabytes!(4)
+#[macro_export]
+macro_rules! terabytes {
+    ($num_terabytes:expr) => {
+        $crate::datasize::DataSize::new_from_terabytes($num_terabytes as u64)
+    }
+}
+
+/// Create a DataSize from a number of kibibytes
+/// ex: kibibytes!(4)
+#[macro_export]
+macro_rules! kibibytes {
+    ($num_kibibytes:expr) => {
+        $crate::datasize::DataSize::new_from_kibibytes($num_kibibytes as u64)
+    }
+}
+
+/// Create a DataSize from a number of mebibytes
+/// ex: mebibytes!(4)
+#[macro_export]
+macro_rules! mebibytes {
+    ($num_mebibytes:expr) => {
+        $crate::datasize::DataSize::new_from_mebibytes($num_mebibytes as u64)
+    }
+}
+
+/// Create a DataSize from a number of gibibytes
+/// ex: gibibytes!(4)
+#[macro_export]
+macro_rules! gibibytes {
+    ($num_gibibytes:expr) => {
+        $crate::datasize::DataSize::new_from_gibibytes($num_gibibytes as u64)
+    }
+}
+
+/// Create a DataSize from a number of tebibytes
+/// ex: tebibytes!(4)
+#[macro_export]
+macro_rules! tebibytes {
+    ($num_tebibytes:expr) => {
+        $crate::datasize::DataSize::new_from_tebibytes($num_tebibytes as u64)
     }
 }
 
@@ -44,7 +98,13 @@ macro_rules! datasize {
             "bytes" => bytes!($amount),
             "kilobytes" => kilobytes!($amount),
             "megabytes" => megabytes!($amount),
-            val @ _ => panic!("Unsupported size {}", val)
+            "gigabytes" => gigabytes!($amount),
+            "terabytes" => terabytes!($amount),
+            "kibibytes" => kibibytes!($amount),
+            "mebibytes" => mebibytes!($amount),
+            "gibibytes" => gibibytes!($amount),
+            "tebibytes" => tebibytes!($amount),
+            val => panic!("Unsupported size {}", val)
         }
     };
 }
@@ -58,10 +118,22 @@ mod tests {
         assert_eq!(bytes!(4), crate::datasize::DataSize::new_from_bytes(4));
         assert_eq!(kilobytes!(4), crate::datasize::DataSize::new_from_kilobytes(4));
         assert_eq!(megabytes!(4), crate::datasize::DataSize::new_from_megabytes(4));
+        assert_eq!(gigabytes!(4), crate::datasize::DataSize::new_from_gigabytes(4));
+        assert_eq!(terabytes!(4), crate::datasize::DataSize::new_from_terabytes(4));
+        assert_eq!(kibibytes!(4), crate::datasize::DataSize::new_from_kibibytes(4));
+        assert_eq!(mebibytes!(4), crate::datasize::DataSize::new_from_mebibytes(4));
+        assert_eq!(gibibytes!(4), crate::datasize::DataSize::new_from_gibibytes(4));
+        assert_eq!(tebibytes!(4), crate::datasize::DataSize::new_from_tebibytes(4));
         assert_eq!(datasize!(2 bits), bits!(2));
         assert_eq!(datasize!(2 bytes), bytes!(2));
         assert_eq!(datasize!(2 kilobytes), kilobytes!(2));
         assert_eq!(datasize!(2 megabytes), megabytes!(2));
+        assert_eq!(datasize!(2 gigabytes), gigabytes!(2));
+        assert_eq!(datasize!(2 terabytes), terabytes!(2));
+        assert_eq!(datasize!(2 kibibytes), kibibytes!(2));
+        assert_eq!(datasize!(2 mebibytes), mebibytes!(2));
+        assert_eq!(datasize!(2 gibibytes), gibibytes!(2));
+        assert_eq!(datasize!(2 tebibytes), tebibytes!(2));
     }
 
     #[test]
@@ -7,10 +7,7 @@ pub trait Fits {
 /// Whether or not the value of a u32 can fit into a given [DataSize]
 impl Fits for u32 {
     fn fits_in(&self, size: &DataSize) -> bool {
-        match self {
-            _f if size.max_value() >= *self => true,
-            _ => false
-        }
+        size.max_value() >= *self as u64
     }
 }
 
@@ -20,7 +17,7 @@ mod tests {
 
     #[test]
     fn test_fits() {
-        assert_eq!(3u32.fits_in(&datasize!(3 bits)), true);
-        assert_eq!(3u32.fits_in(&datasize!(1 bits)), false);
+        assert!(3u32.fits_in(&datasize!(3 bits)));
+        assert!(!3u32.fits_in(&datasize!(1 bits)));
     }
 }
@@ -0,0 +1,90 @@
+//! Optional `serde` support for [DataSize], enabled via the `serde` feature.
+//!
+//! The default [Serialize]/[Deserialize] impls use a compact numeric form (the raw
+//! bit count). To serialize as a human-readable string instead (e.g. `"4 kilobytes"`),
+//! annotate the field with `#[serde(with = "rust_datasize::serde_support::as_string")]`.
+
+use crate::datasize::DataSize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for DataSize {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for DataSize {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<DataSize, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let num_bits = u64::deserialize(deserializer)?;
+        Ok(DataSize::new_from_bits(num_bits))
+    }
+}
+
+/// Serialize/deserialize a [DataSize] as a human-readable string (e.g. `"4 kilobytes"`)
+/// rather than the default compact numeric form. Use via
+/// `#[serde(with = "rust_datasize::serde_support::as_string")]` on a struct field.
+///
+/// Requires the `alloc` feature, since the string form is heap-allocated.
+#[cfg(feature = "alloc")]
+pub mod as_string {
+    use super::DataSize;
+    use alloc::string::{String, ToString};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(size: &DataSize, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        size.to_string().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> core::result::Result<DataSize, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[test]
+    fn test_numeric_round_trip() {
+        let size = DataSize::new_from_kilobytes(4);
+        let json = serde_json::to_string(&size).unwrap();
+        assert_eq!(json, "32000");
+        assert_eq!(serde_json::from_str::<DataSize>(&json).unwrap(), size);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_support::as_string")]
+        max_payload: DataSize,
+    }
+
+    #[test]
+    fn test_string_representation_round_trip() {
+        let config = Config { max_payload: DataSize::new_from_kilobytes(4) };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"max_payload":"4 kilobytes"}"#);
+
+        let parsed: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.max_payload, DataSize::new_from_kilobytes(4));
+    }
+
+    #[test]
+    fn test_string_representation_rejects_unknown_unit() {
+        let json = r#"{"max_payload":"4 petabytes"}"#;
+        assert!(serde_json::from_str::<Config>(json).is_err());
+    }
+}